@@ -3,9 +3,11 @@
 
 ## Features
 
-- parses SQL scripts (currently MySQL) to sequence of separate SQL statements.
+- parses SQL scripts to sequence of separate SQL statements.
 - marks parts of the SQL statement as different token types (keywords, strings, comments, ...).
 - not validating input, only splits SQL statements without checking that they are valid.
+- defaults to MySQL conventions, but [`Dialect`] lets other SQL flavors (e.g. PostgreSQL) plug in their own comment, string and identifier rules via `SqlScriptParser::new_with_dialect`.
+- optional `serde` feature derives `Serialize`/`Deserialize` on `SqlToken`/`SqlTokenKind`, plus owned `OwnedSqlScript`/`OwnedSqlToken` projections for caching or transmitting parsed output.
 
 ## Usage
 
@@ -53,13 +55,14 @@ impl<'a> SqlScriptTokenizer<'a, SqlStatement<'a>> for DmlDdlSqlScriptTokenizer {
         let mut tokens_general = tokens.iter().filter(|x| {
             [
                 SqlTokenKind::Word,
+                SqlTokenKind::Keyword,
                 SqlTokenKind::Symbol,
                 SqlTokenKind::String,
             ]
             .contains(&x.kind)
         });
         let kind = if let Some(first_keyword) = tokens_general.next() {
-            if first_keyword.kind == SqlTokenKind::Word {
+            if first_keyword.kind == SqlTokenKind::Keyword {
                 let token = std::str::from_utf8(first_keyword.extract(&sql_script))
                     .unwrap()
                     .to_lowercase();
@@ -90,6 +93,170 @@ assert_eq!(parser.next(), None);
 
 */
 
+/// Describes the SQL dialect understood by a [`SqlScriptParser`].
+///
+/// Mirrors the dialect design used by `sqlparser-rs`: low-level matchers
+/// (`word`, `line_comment`, `string`, the statement terminator check in
+/// `read_statement`) ask the active dialect instead of hardcoding MySQL
+/// conventions, so the same parser can be reused for other SQL flavors.
+pub trait Dialect {
+    /// Returns `true` if `ch` can start an identifier/word.
+    fn is_identifier_start(&self, ch: u8) -> bool {
+        ch.is_ascii_alphabetic() || ch == b'_'
+    }
+
+    /// Returns `true` if `ch` can continue an identifier/word.
+    fn is_identifier_part(&self, ch: u8) -> bool {
+        ch.is_ascii_alphanumeric() || ch == b'_'
+    }
+
+    /// Returns `true` if `#` starts a line comment in this dialect.
+    fn supports_hash_comment(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if `--` must be followed by a space to start a line
+    /// comment. MySQL requires the space; PostgreSQL does not.
+    ///
+    /// Behavior change: as of the `DELIMITER`-directive fix, a bare `--`
+    /// immediately followed by end of line (no space) is also accepted when
+    /// this returns `true`, so mysqldump's bare `--` banner lines are
+    /// recognized as comments rather than tokenizing as two `Symbol`s.
+    fn requires_line_comment_space(&self) -> bool {
+        true
+    }
+
+    /// Characters that can delimit a string/quoted identifier.
+    fn string_quote_chars(&self) -> &'static [u8] {
+        b"'\"`"
+    }
+
+    /// The default statement terminator, e.g. `;`.
+    fn statement_delimiter(&self) -> &'static [u8] {
+        b";"
+    }
+
+    /// Returns `true` if `$tag$ ... $tag$` dollar-quoted strings are
+    /// recognized by this dialect.
+    fn supports_dollar_quoted_string(&self) -> bool {
+        false
+    }
+
+    /// Dialect-specific reserved words, on top of the common ANSI keywords
+    /// the parser always checks. Checked case-insensitively; a matched
+    /// `Word` token is promoted to [`SqlTokenKind::Keyword`].
+    fn keywords(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Keywords common to all dialects shipped here, similar in spirit to
+/// sqlparser's `ALL_KEYWORDS`, trimmed to what's useful for statement-kind
+/// detection and highlighting rather than an exhaustive reserved-word list.
+const ANSI_KEYWORDS: &[&str] = &[
+    "SELECT", "INSERT", "UPDATE", "DELETE", "FROM", "WHERE", "INTO", "VALUES", "SET", "JOIN",
+    "INNER", "LEFT", "RIGHT", "OUTER", "ON", "AND", "OR", "NOT", "NULL", "IS", "IN", "AS",
+    "DISTINCT", "GROUP", "BY", "ORDER", "HAVING", "LIMIT", "OFFSET", "UNION", "ALL", "CREATE",
+    "ALTER", "DROP", "TABLE", "INDEX", "VIEW", "PRIMARY", "FOREIGN", "KEY", "REFERENCES",
+    "DEFAULT", "CHECK", "UNIQUE", "CASE", "WHEN", "THEN", "ELSE", "END", "BEGIN", "COMMIT",
+    "ROLLBACK", "TRANSACTION", "GRANT", "REVOKE",
+];
+
+/// MySQL reserved words, in addition to [`ANSI_KEYWORDS`].
+const MYSQL_KEYWORDS: &[&str] = &[
+    "AUTO_INCREMENT",
+    "ENGINE",
+    "UNSIGNED",
+    "REPLACE",
+    "IGNORE",
+    "DELIMITER",
+    "PROCEDURE",
+    "FUNCTION",
+    "TRIGGER",
+    "CHARSET",
+];
+
+/// PostgreSQL reserved words, in addition to [`ANSI_KEYWORDS`].
+const POSTGRESQL_KEYWORDS: &[&str] = &[
+    "RETURNING",
+    "ILIKE",
+    "SERIAL",
+    "LATERAL",
+    "USING",
+    "ONLY",
+    "CASCADE",
+    "SEQUENCE",
+    "EXTENSION",
+    "LANGUAGE",
+];
+
+/// MySQL dialect: `-- ` line comments (space required), `#` line comments,
+/// and backtick-quoted identifiers.
+#[derive(Debug, Default)]
+pub struct MySqlDialect;
+
+impl Dialect for MySqlDialect {
+    fn supports_hash_comment(&self) -> bool {
+        true
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        MYSQL_KEYWORDS
+    }
+}
+
+/// PostgreSQL dialect: `--` line comments without a mandatory space, no
+/// backtick-quoted identifiers, and dollar-quoted string bodies.
+#[derive(Debug, Default)]
+pub struct PostgreSqlDialect;
+
+impl Dialect for PostgreSqlDialect {
+    fn requires_line_comment_space(&self) -> bool {
+        false
+    }
+
+    fn string_quote_chars(&self) -> &'static [u8] {
+        b"'\""
+    }
+
+    fn supports_dollar_quoted_string(&self) -> bool {
+        true
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        POSTGRESQL_KEYWORDS
+    }
+}
+
+/// A permissive dialect that only relies on the common-denominator
+/// defaults from [`Dialect`]. Useful when the exact SQL flavor is unknown.
+#[derive(Debug, Default)]
+pub struct GenericDialect;
+
+impl Dialect for GenericDialect {}
+
+/// A 1-based line/column position in the source.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self { line: 1, column: 1 }
+    }
+}
+
+/// A source range expressed as a pair of [`Location`]s.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: Location,
+    pub end: Location,
+}
+
 /// SQL script single statement.
 pub struct SqlScript<'a> {
     /// Start index in source.
@@ -99,6 +266,44 @@ pub struct SqlScript<'a> {
     /// SQL Statement.
     /// Includes SQL statement and all trailing whitespaces and comments.
     pub statement: &'a [u8],
+    /// Line/column span of the statement in the source.
+    pub span: Span,
+}
+
+/// Owned counterpart of [`SqlScript`]. `SqlScript::statement` borrows from
+/// the original source, so it can't round-trip through serialization on its
+/// own; this copies the statement out so the result outlives the source.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedSqlScript {
+    pub start: usize,
+    pub end: usize,
+    pub statement: Vec<u8>,
+    pub span: Span,
+}
+
+impl From<&SqlScript<'_>> for OwnedSqlScript {
+    fn from(sql_script: &SqlScript<'_>) -> Self {
+        Self {
+            start: sql_script.start,
+            end: sql_script.end,
+            statement: sql_script.statement.to_vec(),
+            span: sql_script.span,
+        }
+    }
+}
+
+/// Owned counterpart of [`SqlToken`], carrying its own extracted text
+/// (see [`SqlToken::owned`]) so a tokenized script can be cached or
+/// transmitted and rehydrated without re-parsing.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedSqlToken {
+    pub start: usize,
+    pub end: usize,
+    pub kind: SqlTokenKind,
+    pub span: Span,
+    pub text: Vec<u8>,
 }
 
 pub trait SqlScriptTokenizer<'a, Y> {
@@ -110,6 +315,14 @@ pub struct SqlScriptParser<'a, Y, T: SqlScriptTokenizer<'a, Y>> {
     source: &'a [u8],
     position: usize,
     tokenizer: T,
+    dialect: Box<dyn Dialect>,
+    /// Active statement delimiter. Starts as `dialect.statement_delimiter()`
+    /// and can be overridden by a `DELIMITER <token>` directive (MySQL),
+    /// e.g. to carve out `CREATE PROCEDURE ... END$$` as one statement.
+    current_delimiter: Vec<u8>,
+    /// Line/column of `position`, updated incrementally as bytes are
+    /// consumed so matchers don't each need to track newlines themselves.
+    location: Location,
     _p: std::marker::PhantomData<Y>,
 }
 
@@ -117,11 +330,14 @@ const SP: &[u8] = b" \t\r\n";
 const SP_WO_LF: &[u8] = b" \t\r";
 
 /// SQL token. Start and end are indexes in source (global) array.
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SqlToken {
     pub start: usize,
     pub end: usize,
     pub kind: SqlTokenKind,
+    /// Line/column span of the token in the source.
+    pub span: Span,
 }
 
 impl SqlToken {
@@ -129,19 +345,37 @@ impl SqlToken {
     pub fn extract<'a>(&self, sql_script: &SqlScript<'a>) -> &'a [u8] {
         &sql_script.statement[self.start - sql_script.start..self.end - sql_script.start]
     }
+
+    /// Projects this token into an [`OwnedSqlToken`] by extracting and
+    /// copying its text out of `sql_script`.
+    pub fn owned(&self, sql_script: &SqlScript) -> OwnedSqlToken {
+        OwnedSqlToken {
+            start: self.start,
+            end: self.end,
+            kind: self.kind,
+            span: self.span,
+            text: self.extract(sql_script).to_vec(),
+        }
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SqlTokenKind {
     Space,
     Comment,
     Word,
+    /// A `Word` recognized as reserved by the active dialect's keyword set.
+    Keyword,
     String,
     Symbol,
 }
 
 type SqlTokenPos = (SqlToken, usize);
 
+/// A low-level matcher function, as used by `SqlScriptParser::first_of`.
+type Matcher<'a, Y, T> = fn(&SqlScriptParser<'a, Y, T>, usize) -> Option<SqlTokenPos>;
+
 /// Default no-op SQL script tokenizer. Just returns `SqlScript`.
 pub struct DefaultSqlScriptTokenizer;
 
@@ -171,20 +405,42 @@ impl<'a> SqlScriptTokenizer<'a, SqlScript<'a>> for DefaultSqlScriptTokenizer {
 }
 
 impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
+    /// Creates a parser using the [`MySqlDialect`], the parser's default
+    /// dialect.
     pub fn new(tokenizer: T, source: &'a [u8]) -> Self {
+        Self::new_with_dialect(MySqlDialect, tokenizer, source)
+    }
+
+    /// Creates a parser that uses `dialect` to drive comment, string and
+    /// identifier matching.
+    pub fn new_with_dialect(dialect: impl Dialect + 'static, tokenizer: T, source: &'a [u8]) -> Self {
+        let dialect = Box::new(dialect);
+        let current_delimiter = dialect.statement_delimiter().to_vec();
         Self {
             source,
             position: 0,
             tokenizer,
+            dialect,
+            current_delimiter,
+            location: Location::default(),
             _p: std::marker::PhantomData,
         }
     }
 
-    fn first_of(
-        &self,
-        matchers: &[fn(&SqlScriptParser<'a, Y, T>, usize) -> Option<SqlTokenPos>],
-        position: usize,
-    ) -> Option<SqlTokenPos> {
+    /// Advances `self.location` past `self.source[from..to]`, tracking line
+    /// breaks so every subsequently read token gets an accurate [`Span`].
+    fn advance_location(&mut self, from: usize, to: usize) {
+        for &ch in &self.source[from..to] {
+            if ch == b'\n' {
+                self.location.line += 1;
+                self.location.column = 1;
+            } else {
+                self.location.column += 1;
+            }
+        }
+    }
+
+    fn first_of(&self, matchers: &[Matcher<'a, Y, T>], position: usize) -> Option<SqlTokenPos> {
         for matcher in matchers {
             let result = matcher(self, position);
             if result.is_some() {
@@ -213,6 +469,7 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
                     start: position,
                     end: x,
                     kind: SqlTokenKind::Space,
+                    span: Span::default(),
                 },
                 x,
             )
@@ -223,7 +480,7 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
         self.source
             .get(position)
             .filter(|x| pattern.contains(x))
-            .and_then(|_| {
+            .map(|_| {
                 let mut position = position + 1;
                 while let Some(ch) = self.source.get(position) {
                     if !pattern.contains(ch) {
@@ -231,63 +488,110 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
                     }
                     position += 1;
                 }
-                Some(position)
+                position
             })
     }
 
     fn word(&self, position: usize) -> Option<SqlTokenPos> {
         let start = position;
         let mut position = position;
-        while let Some(ch) = self.source.get(position) {
-            match ch {
-                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' => position += 1,
-                _ => break,
-            }
-        }
-        if start == position {
+        if !self
+            .source
+            .get(position)
+            .is_some_and(|&ch| self.dialect.is_identifier_start(ch))
+        {
             return None;
         }
+        position += 1;
+        while let Some(&ch) = self.source.get(position) {
+            if !self.dialect.is_identifier_part(ch) {
+                break;
+            }
+            position += 1;
+        }
+        let kind = if self.is_keyword(&self.source[start..position]) {
+            SqlTokenKind::Keyword
+        } else {
+            SqlTokenKind::Word
+        };
         Some((
             SqlToken {
                 start,
                 end: position,
-                kind: SqlTokenKind::Word,
+                kind,
+                span: Span::default(),
             },
             position,
         ))
     }
 
+    /// Checks `lexeme` against the common ANSI keywords plus the active
+    /// dialect's own reserved words, case-insensitively.
+    fn is_keyword(&self, lexeme: &[u8]) -> bool {
+        ANSI_KEYWORDS
+            .iter()
+            .chain(self.dialect.keywords())
+            .any(|keyword| keyword.as_bytes().eq_ignore_ascii_case(lexeme))
+    }
+
+    /// Scans a line comment body (everything up to and including the
+    /// trailing newline, or EOF) once the opening marker has been matched.
+    fn line_comment_body(&self, start: usize, mut position: usize) -> SqlTokenPos {
+        while let Some(c) = self.source.get(position) {
+            position += 1;
+            if c == &b'\n' {
+                break;
+            }
+        }
+        (
+            SqlToken {
+                start,
+                end: position,
+                kind: SqlTokenKind::Comment,
+                span: Span::default(),
+            },
+            position,
+        )
+    }
+
     fn line_comment(&self, position: usize) -> Option<SqlTokenPos> {
         if self.source.get(position) == Some(&b'-') {
             let start = position;
-            let mut position = position + 1;
-            return match (self.source.get(position), self.source.get(position + 1)) {
-                (Some(b'-'), Some(b' ')) => {
-                    position += 2;
-                    while let Some(c) = self.source.get(position) {
-                        position += 1;
-                        if c == &b'\n' {
-                            break;
+            let after_dashes = position + 1;
+            return match self.source.get(after_dashes) {
+                Some(b'-') => {
+                    let body_start = after_dashes + 1;
+                    if self.dialect.requires_line_comment_space() {
+                        // MySQL requires `--` to be followed by a space, with
+                        // one narrow exception: a bare `--` immediately ending
+                        // the line (as in the banner lines mysqldump emits)
+                        // is also accepted. End of input is deliberately not
+                        // included here, to avoid widening this beyond what
+                        // the banner case needs.
+                        match self.source.get(body_start) {
+                            Some(b' ' | b'\r' | b'\n') => {
+                                Some(self.line_comment_body(start, body_start))
+                            }
+                            _ => None,
                         }
+                    } else {
+                        Some(self.line_comment_body(start, body_start))
                     }
-                    Some((
-                        SqlToken {
-                            start,
-                            end: position,
-                            kind: SqlTokenKind::Comment,
-                        },
-                        position,
-                    ))
                 }
                 _ => None,
             };
         }
+        if self.dialect.supports_hash_comment() && self.source.get(position) == Some(&b'#') {
+            return Some(self.line_comment_body(position, position + 1));
+        }
         None
     }
 
     fn string(&self, position: usize) -> Option<SqlTokenPos> {
-        self.source.get(position).and_then(|border| match border {
-            b'\'' | b'"' | b'`' => {
+        self.source
+            .get(position)
+            .filter(|border| self.dialect.string_quote_chars().contains(border))
+            .map(|border| {
                 let start = position;
                 let mut position = position + 1;
                 while let Some(ch) = self.source.get(position) {
@@ -302,17 +606,67 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
                         position += 1;
                     }
                 }
-                Some((
+                (
                     SqlToken {
                         start,
                         end: position,
                         kind: SqlTokenKind::String,
+                        span: Span::default(),
                     },
                     position,
-                ))
+                )
+            })
+    }
+
+    /// Matches a PostgreSQL dollar-quoted string, e.g. `$$ ... $$` or
+    /// `$tag$ ... $tag$`, gated behind `Dialect::supports_dollar_quoted_string`.
+    /// Like the existing string matcher, an unterminated quote runs to EOF.
+    fn dollar_quoted_string(&self, position: usize) -> Option<SqlTokenPos> {
+        if !self.dialect.supports_dollar_quoted_string() {
+            return None;
+        }
+        if self.source.get(position) != Some(&b'$') {
+            return None;
+        }
+        let start = position;
+        let mut tag_end = position + 1;
+        while self
+            .source
+            .get(tag_end)
+            .is_some_and(|&ch| ch.is_ascii_alphanumeric() || ch == b'_')
+        {
+            tag_end += 1;
+        }
+        if self.source.get(tag_end) != Some(&b'$') {
+            return None;
+        }
+        let opening_delimiter = &self.source[start..=tag_end];
+        let mut position = tag_end + 1;
+        loop {
+            match self.source[position..].iter().position(|&ch| ch == b'$') {
+                None => {
+                    position = self.source.len();
+                    break;
+                }
+                Some(offset) => {
+                    let candidate = position + offset;
+                    if self.source[candidate..].starts_with(opening_delimiter) {
+                        position = candidate + opening_delimiter.len();
+                        break;
+                    }
+                    position = candidate + 1;
+                }
             }
-            _ => None,
-        })
+        }
+        Some((
+            SqlToken {
+                start,
+                end: position,
+                kind: SqlTokenKind::String,
+                span: Span::default(),
+            },
+            position,
+        ))
     }
 
     fn multiline_comment(&self, position: usize) -> Option<SqlTokenPos> {
@@ -335,6 +689,7 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
                         start,
                         end: position,
                         kind: SqlTokenKind::Comment,
+                        span: Span::default(),
                     },
                     position,
                 ))
@@ -343,60 +698,182 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> SqlScriptParser<'a, Y, T> {
         }
     }
 
-    fn read_statement(&self, position: &mut usize) -> Option<(usize, &'a [u8], Vec<SqlToken>)> {
+    /// Fills in `token`'s [`Span`] from the current location and advances
+    /// the parser's location past `self.source[from..to]`. Keeps the span
+    /// bookkeeping in this single byte-advance path instead of duplicating
+    /// it in every matcher.
+    fn locate(&mut self, mut token: SqlToken, from: usize, to: usize) -> SqlToken {
+        let start = self.location;
+        self.advance_location(from, to);
+        token.span = Span {
+            start,
+            end: self.location,
+        };
+        token
+    }
+
+    /// Matches a MySQL `DELIMITER <token>` directive at `position`, e.g.
+    /// `DELIMITER $$` or `DELIMITER ;`. Returns the directive's end
+    /// position, the new delimiter bytes and the tokens making up the
+    /// directive, without touching the normal per-statement token loop.
+    fn delimiter_directive(&mut self, position: usize) -> Option<(usize, Vec<u8>, Vec<SqlToken>)> {
+        // Match the whole directive against plain byte positions first,
+        // without touching `self.location` — `word`/`space_without_eol`/`eol`
+        // don't mutate it, only `self.locate` does. That way a failed match
+        // (e.g. `DELIMITER` with no following token) never double-advances
+        // the location for bytes the normal token loop will re-tokenize.
+        let (word, after_word) = self.word(position)?;
+        if !self.source[word.start..word.end].eq_ignore_ascii_case(b"delimiter") {
+            return None;
+        }
+        let (space, after_space) = self.space_without_eol(after_word)?;
+        let delimiter_start = after_space;
+        let mut delimiter_end = delimiter_start;
+        while self.source.get(delimiter_end).is_some_and(|ch| !SP.contains(ch)) {
+            delimiter_end += 1;
+        }
+        if delimiter_end == delimiter_start {
+            return None;
+        }
+        let delimiter = self.source[delimiter_start..delimiter_end].to_vec();
+        let eol_match = self.eol(delimiter_end);
+
+        // The directive is confirmed well-formed, so it's now safe to advance
+        // the real location and build the located tokens.
+        let word = self.locate(word, position, after_word);
+        let space = self.locate(space, after_word, after_space);
+        let symbol = self.locate(
+            SqlToken {
+                start: delimiter_start,
+                end: delimiter_end,
+                kind: SqlTokenKind::Symbol,
+                span: Span::default(),
+            },
+            delimiter_start,
+            delimiter_end,
+        );
+        let mut tokens = vec![word, space, symbol];
+        let mut end = delimiter_end;
+        if let Some((token, after_eol)) = eol_match {
+            let token = self.locate(token, delimiter_end, after_eol);
+            tokens.push(token);
+            end = after_eol;
+        }
+        Some((end, delimiter, tokens))
+    }
+
+    fn read_statement(
+        &mut self,
+        position: &mut usize,
+    ) -> Option<(usize, &'a [u8], Vec<SqlToken>, Span)> {
         if *position == self.source.len() {
             return None;
         }
         let start = *position;
-        let mut end = None;
+        let start_location = self.location;
+        // A `DELIMITER <token>` directive can be preceded by whitespace or
+        // comments (e.g. the `--` banner mysqldump writes before routines),
+        // so keep skipping those and re-checking for the directive rather
+        // than only looking for it at the very start of the statement.
         let mut tokens = vec![];
+        loop {
+            if let Some((end, delimiter, directive_tokens)) = self.delimiter_directive(*position) {
+                self.current_delimiter = delimiter;
+                *position = end;
+                tokens.extend(directive_tokens);
+                let span = Span {
+                    start: start_location,
+                    end: self.location,
+                };
+                return Some((end, &self.source[start..end], tokens, span));
+            }
+            if let Some((token, p)) =
+                self.first_of(&[Self::space, Self::line_comment, Self::multiline_comment], *position)
+            {
+                let token = self.locate(token, *position, p);
+                *position = p;
+                tokens.push(token);
+                continue;
+            }
+            break;
+        }
+        if *position == self.source.len() {
+            let span = Span {
+                start: start_location,
+                end: self.location,
+            };
+            return Some((*position, &self.source[start..*position], tokens, span));
+        }
+        let mut end = None;
         loop {
             if let Some((token, p)) = self.first_of(
                 &[
                     Self::space,
                     Self::line_comment,
                     Self::multiline_comment,
+                    Self::dollar_quoted_string,
                     Self::string,
                     Self::word,
                 ],
                 *position,
             ) {
+                let token = self.locate(token, *position, p);
                 *position = p;
                 tokens.push(token);
-            } else if Some(&b';') == self.source.get(*position) {
+            } else if self
+                .source
+                .get(*position..)
+                .is_some_and(|rest| rest.starts_with(self.current_delimiter.as_slice()))
+            {
                 end = Some(*position);
-                *position += 1;
+                let delimiter_end = *position + self.current_delimiter.len();
+                self.advance_location(*position, delimiter_end);
+                *position = delimiter_end;
                 while let Some((token, p)) = self.first_of(
                     &[Self::space_without_eol, Self::multiline_comment],
                     *position,
                 ) {
+                    let token = self.locate(token, *position, p);
                     *position = p;
                     tokens.push(token);
                 }
                 if let Some((token, p)) = self.line_comment(*position) {
+                    let token = self.locate(token, *position, p);
                     *position = p;
                     tokens.push(token);
                 } else if let Some((token, p)) = self.eol(*position) {
+                    let token = self.locate(token, *position, p);
                     *position = p;
                     tokens.push(token);
                 }
                 break;
             } else {
-                tokens.push(SqlToken {
-                    start: *position,
-                    end: *position + 1,
-                    kind: SqlTokenKind::Symbol,
-                });
+                let token = self.locate(
+                    SqlToken {
+                        start: *position,
+                        end: *position + 1,
+                        kind: SqlTokenKind::Symbol,
+                        span: Span::default(),
+                    },
+                    *position,
+                    *position + 1,
+                );
+                tokens.push(token);
                 *position += 1;
             }
             if *position == self.source.len() {
                 break;
             }
         }
+        let span = Span {
+            start: start_location,
+            end: self.location,
+        };
         Some((
-            end.unwrap_or_else(|| *position),
+            end.unwrap_or(*position),
             &self.source[start..*position],
             tokens,
+            span,
         ))
     }
 }
@@ -409,12 +886,13 @@ impl<'a, Y, T: SqlScriptTokenizer<'a, Y>> Iterator for SqlScriptParser<'a, Y, T>
         let mut position = self.position;
         let item = self
             .read_statement(&mut position)
-            .map(|(end, statement, tokens)| {
+            .map(|(end, statement, tokens, span)| {
                 self.tokenizer.apply(
                     SqlScript {
                         start,
                         end,
                         statement,
+                        span,
                     },
                     &tokens,
                 )
@@ -470,11 +948,250 @@ see it */;
         assert_eq!(sqls[3], b"/**/\nalter table me");
     }
 
+    #[test]
+    fn postgresql_dialect_allows_line_comment_without_space() {
+        let test_script = b"select 1; --comment\nselect 2";
+
+        let parser = SqlScriptParser::new_with_dialect(
+            PostgreSqlDialect,
+            DefaultSqlScriptTokenizer {},
+            test_script,
+        );
+        let sqls: Vec<_> = parser.map(|x| x.statement).collect();
+        assert_eq!(
+            sqls,
+            vec![&b"select 1; --comment\n"[..], &b"select 2"[..]]
+        );
+    }
+
+    #[test]
+    fn mysql_dialect_requires_space_after_line_comment_marker() {
+        let test_script = b"select 1; --comment\nselect 2";
+
+        // Unlike PostgreSQL, MySQL's `--` must be followed by whitespace to
+        // start a comment, so `--comment` here is not recognized as one and
+        // the statement boundary falls before it instead.
+        let sqls: Vec<_> = sql_script_parser(test_script)
+            .map(|x| x.statement)
+            .collect();
+        assert_eq!(
+            sqls,
+            vec![&b"select 1; "[..], &b"--comment\nselect 2"[..]]
+        );
+    }
+
+    struct TestTokenKindsSqlScriptTokenizer;
+    impl<'a> SqlScriptTokenizer<'a, Vec<(SqlTokenKind, Vec<u8>)>> for TestTokenKindsSqlScriptTokenizer {
+        fn apply(&self, sql_script: SqlScript<'a>, tokens: &[SqlToken]) -> Vec<(SqlTokenKind, Vec<u8>)> {
+            tokens
+                .iter()
+                .map(|t| (t.kind, t.extract(&sql_script).to_vec()))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn mysql_dialect_accepts_bare_dashes_at_end_of_line_only() {
+        // A bare `--` immediately ending the line (mysqldump's banner style)
+        // is accepted as a comment, but this is deliberately not widened any
+        // further: a tab after `--`, or `--` sitting at the very end of
+        // input with nothing after it, still tokenize as two `Symbol`s.
+        let eol_kinds =
+            SqlScriptParser::new(TestTokenKindsSqlScriptTokenizer {}, b"select 1 --\n;")
+                .next()
+                .unwrap();
+        assert!(eol_kinds.contains(&(SqlTokenKind::Comment, b"--\n".to_vec())));
+
+        let tab_kinds =
+            SqlScriptParser::new(TestTokenKindsSqlScriptTokenizer {}, b"select 1 --\tfoo;")
+                .next()
+                .unwrap();
+        assert!(tab_kinds.contains(&(SqlTokenKind::Symbol, b"-".to_vec())));
+        assert!(!tab_kinds.iter().any(|(k, _)| *k == SqlTokenKind::Comment));
+
+        let eof_kinds = SqlScriptParser::new(TestTokenKindsSqlScriptTokenizer {}, b"select 1 --")
+            .next()
+            .unwrap();
+        assert!(eof_kinds.contains(&(SqlTokenKind::Symbol, b"-".to_vec())));
+        assert!(!eof_kinds.iter().any(|(k, _)| *k == SqlTokenKind::Comment));
+    }
+
+    #[test]
+    fn generic_dialect_does_not_support_dollar_quoted_strings() {
+        let test_script = b"select $$ a; b $$ from foo;";
+
+        // `GenericDialect` sticks to the common-denominator defaults, so the
+        // `;` inside the `$$ ... $$` body still ends the statement, unlike
+        // `PostgreSqlDialect` which would treat it as one quoted string.
+        let parser = SqlScriptParser::new_with_dialect(
+            GenericDialect,
+            DefaultSqlScriptTokenizer {},
+            test_script,
+        );
+        let sqls: Vec<_> = parser.map(|x| x.statement).collect();
+        assert_eq!(sqls, vec![&b"select $$ a; "[..], &b"b $$ from foo;"[..]]);
+    }
+
+    #[test]
+    fn delimiter_directive_after_mysqldump_banner_keeps_procedure_body_intact() {
+        // Regression test: a `DELIMITER` directive preceded by a mysqldump-style
+        // `--` banner (and a blank line) used to be invisible to
+        // `read_statement`, so the temporary `;;` delimiter was never applied
+        // and the procedure body got shredded on its inner `SELECT 1;`.
+        let test_script = b"-- MySQL dump 10.13\n--\n-- Host: localhost\n--\n\n\
+DELIMITER ;;\n\
+CREATE PROCEDURE foo()\nBEGIN\n  SELECT 1;\nEND ;;\n\
+DELIMITER ;\n";
+
+        let sqls: Vec<_> = sql_script_parser(test_script)
+            .map(|x| x.statement)
+            .collect();
+        assert_eq!(
+            sqls,
+            vec![
+                &b"-- MySQL dump 10.13\n--\n-- Host: localhost\n--\n\nDELIMITER ;;\n"[..],
+                &b"CREATE PROCEDURE foo()\nBEGIN\n  SELECT 1;\nEND ;;\n"[..],
+                &b"DELIMITER ;\n"[..],
+            ]
+        );
+    }
+
+    #[test]
+    fn statement_spans_track_line_and_column_across_embedded_newlines() {
+        let test_script = b"select 1;\n/* multi\nline */\nselect 2;";
+
+        let spans: Vec<_> = sql_script_parser(test_script).map(|x| x.span).collect();
+        assert_eq!(
+            spans,
+            vec![
+                Span {
+                    start: Location { line: 1, column: 1 },
+                    end: Location { line: 2, column: 1 },
+                },
+                Span {
+                    start: Location { line: 2, column: 1 },
+                    end: Location { line: 4, column: 10 },
+                },
+            ]
+        );
+    }
+
+    struct TestRejectedDelimiterSqlScriptTokenizer;
+    impl<'a> SqlScriptTokenizer<'a, SqlScript<'a>> for TestRejectedDelimiterSqlScriptTokenizer {
+        fn apply(&self, sql_script: SqlScript<'a>, tokens: &[SqlToken]) -> SqlScript<'a> {
+            // `DELIMITER;` has no space before the delimiter token, so
+            // `delimiter_directive` must reject it without having already
+            // advanced `self.location` for the `DELIMITER` word — otherwise
+            // this span would be doubled up from the directive match attempt
+            // plus the normal re-tokenization of the same bytes.
+            assert_eq!(
+                tokens[0].span,
+                Span {
+                    start: Location { line: 1, column: 1 },
+                    end: Location { line: 1, column: 10 },
+                }
+            );
+            sql_script
+        }
+    }
+
+    #[test]
+    fn rejected_delimiter_directive_does_not_double_count_its_span() {
+        let test_script = b"DELIMITER;\n";
+        let parser =
+            SqlScriptParser::new(TestRejectedDelimiterSqlScriptTokenizer {}, test_script);
+        assert_eq!(parser.count(), 1);
+    }
+
+    struct TestKeywordSqlScriptTokenizer;
+    impl<'a> SqlScriptTokenizer<'a, SqlScript<'a>> for TestKeywordSqlScriptTokenizer {
+        fn apply(&self, sql_script: SqlScript<'a>, tokens: &[SqlToken]) -> SqlScript<'a> {
+            let words: Vec<_> = tokens
+                .iter()
+                .filter(|t| matches!(t.kind, SqlTokenKind::Word | SqlTokenKind::Keyword))
+                .map(|t| (t.extract(&sql_script), t.kind))
+                .collect();
+            assert_eq!(
+                words,
+                vec![
+                    (&b"select"[..], SqlTokenKind::Keyword),
+                    (&b"foo"[..], SqlTokenKind::Word),
+                    (&b"from"[..], SqlTokenKind::Keyword),
+                    (&b"bar"[..], SqlTokenKind::Word),
+                ]
+            );
+            sql_script
+        }
+    }
+
+    #[test]
+    fn reserved_words_are_classified_as_keywords() {
+        let test_script = b"select foo from bar;";
+        let parser = SqlScriptParser::new(TestKeywordSqlScriptTokenizer {}, test_script);
+        assert_eq!(parser.count(), 1);
+    }
+
+    #[test]
+    fn dollar_quoted_function_body_is_not_split_on_inner_semicolons() {
+        let test_script = b"CREATE FUNCTION foo() RETURNS int AS $$\n\
+BEGIN\n  RETURN 1;\nEND;\n\
+$$ LANGUAGE plpgsql;\n\
+select 1;";
+
+        let parser = SqlScriptParser::new_with_dialect(
+            PostgreSqlDialect,
+            DefaultSqlScriptTokenizer {},
+            test_script,
+        );
+        let sqls: Vec<_> = parser.map(|x| x.statement).collect();
+        assert_eq!(
+            sqls,
+            vec![
+                &b"CREATE FUNCTION foo() RETURNS int AS $$\nBEGIN\n  RETURN 1;\nEND;\n$$ LANGUAGE plpgsql;\n"[..],
+                &b"select 1;"[..],
+            ]
+        );
+    }
+
+    struct TestOwnedSqlScriptTokenizer;
+    impl<'a> SqlScriptTokenizer<'a, OwnedSqlScript> for TestOwnedSqlScriptTokenizer {
+        fn apply(&self, sql_script: SqlScript<'a>, tokens: &[SqlToken]) -> OwnedSqlScript {
+            let owned_tokens: Vec<_> = tokens.iter().map(|t| t.owned(&sql_script)).collect();
+            assert_eq!(owned_tokens[0].text, sql_script.statement[..6].to_vec());
+            assert_eq!(
+                tokens[0].extract(&sql_script),
+                &sql_script.statement[..6]
+            );
+            OwnedSqlScript::from(&sql_script)
+        }
+    }
+
+    #[test]
+    fn owned_sql_token_and_script_project_text_out_of_the_source() {
+        let test_script = b"select 1;";
+        let parser = SqlScriptParser::new(TestOwnedSqlScriptTokenizer {}, test_script);
+        let owned: Vec<_> = parser.collect();
+        assert_eq!(owned.len(), 1);
+        assert_eq!(owned[0].statement, test_script.to_vec());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn owned_sql_script_round_trips_through_json() {
+        let test_script = b"select 1;\nselect 2";
+        let sql_script = sql_script_parser(test_script).next().unwrap();
+        let owned = OwnedSqlScript::from(&sql_script);
+
+        let json = serde_json::to_string(&owned).unwrap();
+        let restored: OwnedSqlScript = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, owned);
+    }
+
     struct TestCommentSqlScriptTokenizer;
     impl<'a> SqlScriptTokenizer<'a, SqlScript<'a>> for TestCommentSqlScriptTokenizer {
         fn apply(&self, sql_script: SqlScript<'a>, tokens: &[SqlToken]) -> SqlScript<'a> {
             assert_eq!(
-                tokens.get(0).map(|x| x.extract(&sql_script)),
+                tokens.first().map(|x| x.extract(&sql_script)),
                 Some(&b"/* comment */"[..])
             );
             sql_script